@@ -15,9 +15,15 @@
 /// Supports the following traits:
 /// - From -- converts from the wrapped type to the newtype
 /// - Into -- consumes the alias type and returns the wrapped type
+/// - FromStr -- parses the wrapped type and wraps the result
 /// - Deref -- provides a reference to the wrapped type
 /// - DerefMut -- provides a mutable reference to the wrapped type
+/// - AsRef -- borrows the newtype as a reference to the wrapped type
+/// - AsMut -- borrows the newtype as a mutable reference to the wrapped type
+/// - Borrow -- borrows the newtype as the wrapped type
+/// - BorrowMut -- mutably borrows the newtype as the wrapped type
 /// - Display -- delegates to the wrapped type for display
+/// - Constructor -- an inherent `new` associated function wrapping the value
 /// - The following arithmetic traits which delegate to the wrapped type
 ///   (and which require implementations of From and Into):
 /// -- Add
@@ -25,6 +31,50 @@
 /// -- Mul
 /// -- Div
 /// -- Neg
+/// - The following compound-assignment traits which delegate in place to the
+///   wrapped type:
+/// -- AddAssign
+/// -- SubAssign
+/// -- MulAssign
+/// -- DivAssign
+/// -- RemAssign
+/// - The following scalar operator traits which operate against the wrapped
+///   type directly rather than another alias. Only the `$alias op $t`
+///   direction is emitted; the reflexive `$t op $alias` (e.g. `2.0 * Meters`)
+///   is out of scope, as the orphan rule would force a separate impl per
+///   scalar type:
+/// -- AddScalar
+/// -- SubScalar
+/// -- MulScalar
+/// -- DivScalar
+/// -- RemScalar
+/// - The following bitwise and logical traits which delegate to the wrapped
+///   type:
+/// -- Not
+/// -- BitAnd
+/// -- BitOr
+/// -- BitXor
+/// -- Shl
+/// -- Shr
+/// - The following iterator-folding traits which fold over the wrapped type
+///   (and which require implementations of From and Into):
+/// -- Sum
+/// -- Product
+///
+/// # Generics
+/// A wrapper with type parameters may be written as `$alias<$($g),*>($t)`, and
+/// any bounds the delegated trait bodies require can be supplied through an
+/// optional `where { .. }` clause placed before the keyword list. Only the
+/// single-field form is matched: multi-field generic wrappers such as
+/// `struct Id<T>(u64, PhantomData<T>)` are not supported and will fail to
+/// match the macro.
+/// ```
+/// # #[macro_use] extern crate newtype_macros;
+/// # fn main() {
+/// struct Wrapper<T>(T);
+/// newtype_derive!(Wrapper<T>(T): Deref);
+/// # }
+/// ```
 ///
 /// # Examples
 /// ```
@@ -42,9 +92,11 @@
 /// ```
 #[macro_export]
 macro_rules! newtype_derive {
-    ($alias:ident($t:ty): ) => { };
-    ($alias:ident($t:ty): Deref) => {
-        impl ::std::ops::Deref for $alias {
+    // Internal impl emitters. Each carries the wrapper's generic parameter list
+    // and an optional `where` predicate list so the same body serves both the
+    // plain and the generic matchers; for a non-generic newtype both are empty.
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Deref) => {
+        impl<$($g)*> ::std::ops::Deref for $alias<$($g)*> where $($w)* {
             type Target = $t;
             fn deref<'a>(&'a self) -> &'a $t {
                 let &$alias(ref v) = self;
@@ -52,99 +104,330 @@ macro_rules! newtype_derive {
             }
         }
     };
-    ($alias:ident($t:ty): DerefMut) => {
-        impl ::std::ops::DerefMut for $alias {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): DerefMut) => {
+        impl<$($g)*> ::std::ops::DerefMut for $alias<$($g)*> where $($w)* {
             fn deref_mut<'a>(&'a mut self) -> &'a mut $t {
                 let &mut $alias(ref mut v) = self;
                 v
             }
         }
     };
-    ($alias:ident($t:ty): From) => {
-        impl ::std::convert::From<$t> for $alias {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): From) => {
+        impl<$($g)*> ::std::convert::From<$t> for $alias<$($g)*> where $($w)* {
             fn from(v: $t) -> Self {
                 $alias(v)
             }
         }
     };
-    ($alias:ident($t:ty): Into) => {
-        impl ::std::convert::Into<$t> for $alias {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Into) => {
+        impl<$($g)*> ::std::convert::Into<$t> for $alias<$($g)*> where $($w)* {
             fn into(self) -> $t {
                 let $alias(v) = self;
                 v
             }
         }
     };
-    ($alias:ident($t:ty): Display) => {
-        impl ::std::fmt::Display for $alias {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Display) => {
+        impl<$($g)*> ::std::fmt::Display for $alias<$($g)*> where $($w)* {
              fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 let $alias(ref v) = *self;
                 <$t as ::std::fmt::Display>::fmt(v, f)
             }
         }
     };
-    ($alias:ident($t:ty): Add) => {
-        impl ::std::ops::Add for $alias {
-            type Output = $alias;
-            fn add(self, rhs: $alias) -> Self {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Add) => {
+        impl<$($g)*> ::std::ops::Add for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn add(self, rhs: $alias<$($g)*>) -> Self {
                 let l = ::std::convert::Into::<$t>::into(self);
                 let r = ::std::convert::Into::<$t>::into(rhs);
                 ::std::convert::From::<$t>::from(l.add(r))
             }
         }
     };
-    ($alias:ident($t:ty): Sub) => {
-        impl ::std::ops::Sub for $alias {
-            type Output = $alias;
-            fn sub(self, rhs: $alias) -> Self {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Sub) => {
+        impl<$($g)*> ::std::ops::Sub for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn sub(self, rhs: $alias<$($g)*>) -> Self {
                 let l = ::std::convert::Into::<$t>::into(self);
                 let r = ::std::convert::Into::<$t>::into(rhs);
                 ::std::convert::From::<$t>::from(l.sub(r))
             }
         }
     };
-    ($alias:ident($t:ty): Mul) => {
-        impl ::std::ops::Mul for $alias {
-            type Output = $alias;
-            fn mul(self, rhs: $alias) -> Self {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Mul) => {
+        impl<$($g)*> ::std::ops::Mul for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn mul(self, rhs: $alias<$($g)*>) -> Self {
                 let l = ::std::convert::Into::<$t>::into(self);
                 let r = ::std::convert::Into::<$t>::into(rhs);
                 ::std::convert::From::<$t>::from(l.mul(r))
             }
         }
     };
-    ($alias:ident($t:ty): Div) => {
-        impl ::std::ops::Div for $alias {
-            type Output = $alias;
-            fn div(self, rhs: $alias) -> Self {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Div) => {
+        impl<$($g)*> ::std::ops::Div for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn div(self, rhs: $alias<$($g)*>) -> Self {
                 let l = ::std::convert::Into::<$t>::into(self);
                 let r = ::std::convert::Into::<$t>::into(rhs);
                 ::std::convert::From::<$t>::from(l.div(r))
             }
         }
     };
-    ($alias:ident($t:ty): Rem) => {
-        impl ::std::ops::Rem for $alias {
-            type Output = $alias;
-            fn rem(self, rhs: $alias) -> Self {
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Rem) => {
+        impl<$($g)*> ::std::ops::Rem for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn rem(self, rhs: $alias<$($g)*>) -> Self {
                 let l = ::std::convert::Into::<$t>::into(self);
                 let r = ::std::convert::Into::<$t>::into(rhs);
                 ::std::convert::From::<$t>::from(l.rem(r))
             }
         }
     };
-    ($alias:ident($t:ty): Neg) => {
-        impl ::std::ops::Neg for $alias {
-            type Output = $alias;
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Neg) => {
+        impl<$($g)*> ::std::ops::Neg for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
             fn neg(self) -> Self {
                 let v = ::std::convert::Into::<$t>::into(self);
                 ::std::convert::From::<$t>::from(v.neg())
             }
         }
     };
-    ($alias:ident($t:ty): $keyword:ident) => { unrecognized derive keyword };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): AddAssign) => {
+        impl<$($g)*> ::std::ops::AddAssign for $alias<$($g)*> where $($w)* {
+            fn add_assign(&mut self, rhs: $alias<$($g)*>) {
+                let $alias(ref mut l) = *self;
+                let $alias(r) = rhs;
+                l.add_assign(r);
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): SubAssign) => {
+        impl<$($g)*> ::std::ops::SubAssign for $alias<$($g)*> where $($w)* {
+            fn sub_assign(&mut self, rhs: $alias<$($g)*>) {
+                let $alias(ref mut l) = *self;
+                let $alias(r) = rhs;
+                l.sub_assign(r);
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): MulAssign) => {
+        impl<$($g)*> ::std::ops::MulAssign for $alias<$($g)*> where $($w)* {
+            fn mul_assign(&mut self, rhs: $alias<$($g)*>) {
+                let $alias(ref mut l) = *self;
+                let $alias(r) = rhs;
+                l.mul_assign(r);
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): DivAssign) => {
+        impl<$($g)*> ::std::ops::DivAssign for $alias<$($g)*> where $($w)* {
+            fn div_assign(&mut self, rhs: $alias<$($g)*>) {
+                let $alias(ref mut l) = *self;
+                let $alias(r) = rhs;
+                l.div_assign(r);
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): RemAssign) => {
+        impl<$($g)*> ::std::ops::RemAssign for $alias<$($g)*> where $($w)* {
+            fn rem_assign(&mut self, rhs: $alias<$($g)*>) {
+                let $alias(ref mut l) = *self;
+                let $alias(r) = rhs;
+                l.rem_assign(r);
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): AddScalar) => {
+        impl<$($g)*> ::std::ops::Add<$t> for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn add(self, rhs: $t) -> $alias<$($g)*> {
+                let l = ::std::convert::Into::<$t>::into(self);
+                ::std::convert::From::<$t>::from(l.add(rhs))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): SubScalar) => {
+        impl<$($g)*> ::std::ops::Sub<$t> for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn sub(self, rhs: $t) -> $alias<$($g)*> {
+                let l = ::std::convert::Into::<$t>::into(self);
+                ::std::convert::From::<$t>::from(l.sub(rhs))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): MulScalar) => {
+        impl<$($g)*> ::std::ops::Mul<$t> for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn mul(self, rhs: $t) -> $alias<$($g)*> {
+                let l = ::std::convert::Into::<$t>::into(self);
+                ::std::convert::From::<$t>::from(l.mul(rhs))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): DivScalar) => {
+        impl<$($g)*> ::std::ops::Div<$t> for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn div(self, rhs: $t) -> $alias<$($g)*> {
+                let l = ::std::convert::Into::<$t>::into(self);
+                ::std::convert::From::<$t>::from(l.div(rhs))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): RemScalar) => {
+        impl<$($g)*> ::std::ops::Rem<$t> for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn rem(self, rhs: $t) -> $alias<$($g)*> {
+                let l = ::std::convert::Into::<$t>::into(self);
+                ::std::convert::From::<$t>::from(l.rem(rhs))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): FromStr) => {
+        impl<$($g)*> ::std::str::FromStr for $alias<$($g)*> where $($w)* {
+            type Err = <$t as ::std::str::FromStr>::Err;
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                <$t as ::std::str::FromStr>::from_str(s).map($alias)
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Not) => {
+        impl<$($g)*> ::std::ops::Not for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn not(self) -> Self {
+                let v = ::std::convert::Into::<$t>::into(self);
+                ::std::convert::From::<$t>::from(v.not())
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): BitAnd) => {
+        impl<$($g)*> ::std::ops::BitAnd for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn bitand(self, rhs: $alias<$($g)*>) -> Self {
+                let l = ::std::convert::Into::<$t>::into(self);
+                let r = ::std::convert::Into::<$t>::into(rhs);
+                ::std::convert::From::<$t>::from(l.bitand(r))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): BitOr) => {
+        impl<$($g)*> ::std::ops::BitOr for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn bitor(self, rhs: $alias<$($g)*>) -> Self {
+                let l = ::std::convert::Into::<$t>::into(self);
+                let r = ::std::convert::Into::<$t>::into(rhs);
+                ::std::convert::From::<$t>::from(l.bitor(r))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): BitXor) => {
+        impl<$($g)*> ::std::ops::BitXor for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn bitxor(self, rhs: $alias<$($g)*>) -> Self {
+                let l = ::std::convert::Into::<$t>::into(self);
+                let r = ::std::convert::Into::<$t>::into(rhs);
+                ::std::convert::From::<$t>::from(l.bitxor(r))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Shl) => {
+        impl<$($g)*> ::std::ops::Shl for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn shl(self, rhs: $alias<$($g)*>) -> Self {
+                let l = ::std::convert::Into::<$t>::into(self);
+                let r = ::std::convert::Into::<$t>::into(rhs);
+                ::std::convert::From::<$t>::from(l.shl(r))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Shr) => {
+        impl<$($g)*> ::std::ops::Shr for $alias<$($g)*> where $($w)* {
+            type Output = $alias<$($g)*>;
+            fn shr(self, rhs: $alias<$($g)*>) -> Self {
+                let l = ::std::convert::Into::<$t>::into(self);
+                let r = ::std::convert::Into::<$t>::into(rhs);
+                ::std::convert::From::<$t>::from(l.shr(r))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): AsRef) => {
+        impl<$($g)*> ::std::convert::AsRef<$t> for $alias<$($g)*> where $($w)* {
+            fn as_ref(&self) -> &$t {
+                let $alias(ref v) = *self;
+                v
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): AsMut) => {
+        impl<$($g)*> ::std::convert::AsMut<$t> for $alias<$($g)*> where $($w)* {
+            fn as_mut(&mut self) -> &mut $t {
+                let $alias(ref mut v) = *self;
+                v
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Borrow) => {
+        impl<$($g)*> ::std::borrow::Borrow<$t> for $alias<$($g)*> where $($w)* {
+            fn borrow(&self) -> &$t {
+                let $alias(ref v) = *self;
+                v
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): BorrowMut) => {
+        impl<$($g)*> ::std::borrow::BorrowMut<$t> for $alias<$($g)*> where $($w)* {
+            fn borrow_mut(&mut self) -> &mut $t {
+                let $alias(ref mut v) = *self;
+                v
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Constructor) => {
+        impl<$($g)*> $alias<$($g)*> where $($w)* {
+            /// Constructs a new wrapper.
+            pub fn new(v: $t) -> Self {
+                $alias(v)
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Sum) => {
+        impl<$($g)*> ::std::iter::Sum for $alias<$($g)*> where $($w)* {
+            fn sum<I: ::std::iter::Iterator<Item = $alias<$($g)*>>>(iter: I) -> Self {
+                ::std::convert::From::<$t>::from(::std::iter::Iterator::sum::<$t>(
+                    ::std::iter::Iterator::map(iter, |x| ::std::convert::Into::<$t>::into(x))))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): Product) => {
+        impl<$($g)*> ::std::iter::Product for $alias<$($g)*> where $($w)* {
+            fn product<I: ::std::iter::Iterator<Item = $alias<$($g)*>>>(iter: I) -> Self {
+                ::std::convert::From::<$t>::from(::std::iter::Iterator::product::<$t>(
+                    ::std::iter::Iterator::map(iter, |x| ::std::convert::Into::<$t>::into(x))))
+            }
+        }
+    };
+    (@imp [$($g:tt)*] [$($w:tt)*] $alias:ident($t:ty): $keyword:ident) => { unrecognized derive keyword };
+
+    // Public entry points. The plain forms thread empty generic and `where`
+    // lists; the generic forms thread the captured parameters (and, optionally,
+    // a `where` clause) through each emitted impl.
+    ($alias:ident($t:ty): ) => { };
     ($alias:ident($t:ty): $($keyword:ident),*) => {
-        $(newtype_derive!($alias($t): $keyword);)*
+        $(newtype_derive!(@imp [] [] $alias($t): $keyword);)*
+    };
+    // The generic forms peel off one keyword at a time: a generic parameter
+    // list is itself a repetition, so it cannot be re-expanded inside a second
+    // repetition over the keywords.
+    ($alias:ident<$($g:ident),*>($t:ty) where {$($w:tt)*}: ) => { };
+    ($alias:ident<$($g:ident),*>($t:ty) where {$($w:tt)*}: $keyword:ident $(, $rest:ident)*) => {
+        newtype_derive!(@imp [$($g),*] [$($w)*] $alias($t): $keyword);
+        newtype_derive!($alias<$($g),*>($t) where {$($w)*}: $($rest),*);
+    };
+    ($alias:ident<$($g:ident),*>($t:ty): ) => { };
+    ($alias:ident<$($g:ident),*>($t:ty): $keyword:ident $(, $rest:ident)*) => {
+        newtype_derive!(@imp [$($g),*] [] $alias($t): $keyword);
+        newtype_derive!($alias<$($g),*>($t): $($rest),*);
     };
 }
 
@@ -152,6 +435,10 @@ macro_rules! newtype_derive {
 ///
 /// Supports same traits as newtype_derive!
 ///
+/// Type parameters are accepted with the same `$alias<$($g),*>($t)` spelling
+/// `newtype_derive!` uses; for bound-carrying derives on a generic wrapper,
+/// invoke `newtype_derive!` directly with its `where { .. }` clause.
+///
 /// # Examples
 /// ```
 /// # #[macro_use] extern crate newtype_macros;
@@ -182,6 +469,24 @@ macro_rules! newtype {
 
         $(newtype_derive!($alias($t): $keyword);)*
     };
+    ($(#[$meta:meta])* struct $alias:ident<$($g:ident),*>($t:ty): $($keyword:ident),*) => {
+        $(#[$meta])*
+        struct $alias<$($g),*>($t);
+
+        newtype_derive!($alias<$($g),*>($t): $($keyword),*);
+    };
+    ($(#[$meta:meta])* pub struct $alias:ident<$($g:ident),*>(pub $t:ty): $($keyword:ident),*) => {
+        $(#[$meta])*
+        pub struct $alias<$($g),*>(pub $t);
+
+        newtype_derive!($alias<$($g),*>($t): $($keyword),*);
+    };
+    ($(#[$meta:meta])* pub struct $alias:ident<$($g:ident),*>($t:ty): $($keyword:ident),*) => {
+        $(#[$meta])*
+        pub struct $alias<$($g),*>($t);
+
+        newtype_derive!($alias<$($g),*>($t): $($keyword),*);
+    };
 }
 
 #[test]
@@ -198,6 +503,32 @@ fn test_no_prelude() {
     newtype!(struct M9(i32): From, Into, Div);
     newtype!(struct M10(i32): From, Into, Neg);
     newtype!(#[derive(Hash)] struct M11(i32): Deref);
+    newtype!(struct M12(i32): AddAssign);
+    newtype!(struct M13(i32): SubAssign);
+    newtype!(struct M14(i32): MulAssign);
+    newtype!(struct M15(i32): DivAssign);
+    newtype!(struct M16(i32): RemAssign);
+    newtype!(struct M17(i32): From, Into, AddScalar);
+    newtype!(struct M18(i32): From, Into, SubScalar);
+    newtype!(struct M19(i32): From, Into, MulScalar);
+    newtype!(struct M20(i32): From, Into, DivScalar);
+    newtype!(struct M21(i32): From, Into, RemScalar);
+    newtype!(struct M22(i32): FromStr);
+    newtype!(struct M24(i32): From, Into, Not);
+    newtype!(struct M25(i32): From, Into, BitAnd);
+    newtype!(struct M26(i32): From, Into, BitOr);
+    newtype!(struct M27(i32): From, Into, BitXor);
+    newtype!(struct M28(i32): From, Into, Shl);
+    newtype!(struct M29(i32): From, Into, Shr);
+    newtype!(struct M30<T>(T): Deref, DerefMut);
+    newtype!(pub struct M31<T>(T): Deref);
+    newtype!(struct M32(i32): AsRef);
+    newtype!(struct M33(i32): AsMut);
+    newtype!(struct M34(i32): Borrow);
+    newtype!(struct M35(i32): Borrow, BorrowMut);
+    newtype!(struct M36(i32): Constructor);
+    newtype!(struct M37(i32): From, Into, Sum);
+    newtype!(struct M38(i32): From, Into, Product);
 }
 
 #[cfg(test)]
@@ -273,4 +604,196 @@ mod tests {
         let m = Miles::from(20);
         assert_eq!(Miles::from(-20), -m);
     }
+
+    #[test]
+    fn test_add_assign() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, AddAssign);
+        let mut m = Miles::from(14);
+        m += Miles::from(20);
+        assert_eq!(Miles::from(34), m);
+    }
+
+    #[test]
+    fn test_sub_assign() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, SubAssign);
+        let mut m = Miles::from(20);
+        m -= Miles::from(14);
+        assert_eq!(Miles::from(6), m);
+    }
+
+    #[test]
+    fn test_mul_assign() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, MulAssign);
+        let mut m = Miles::from(14);
+        m *= Miles::from(20);
+        assert_eq!(Miles::from(280), m);
+    }
+
+    #[test]
+    fn test_div_assign() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(f64): From, DivAssign);
+        let mut m = Miles::from(20f64);
+        m /= Miles::from(5f64);
+        assert_eq!(Miles::from(4f64), m);
+    }
+
+    #[test]
+    fn test_rem_assign() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(f64): From, RemAssign);
+        let mut m = Miles::from(20f64);
+        m %= Miles::from(5f64);
+        assert_eq!(Miles::from(0f64), m);
+    }
+
+    #[test]
+    fn test_add_scalar() {
+        newtype!(#[derive(Debug, PartialEq)] struct Meters(f64): From, Into, AddScalar);
+        let m = Meters::from(14f64);
+        assert_eq!(Meters::from(16f64), m + 2f64);
+    }
+
+    #[test]
+    fn test_sub_scalar() {
+        newtype!(#[derive(Debug, PartialEq)] struct Meters(f64): From, Into, SubScalar);
+        let m = Meters::from(14f64);
+        assert_eq!(Meters::from(12f64), m - 2f64);
+    }
+
+    #[test]
+    fn test_mul_scalar() {
+        newtype!(#[derive(Debug, PartialEq)] struct Meters(f64): From, Into, MulScalar);
+        let m = Meters::from(14f64);
+        assert_eq!(Meters::from(28f64), m * 2f64);
+    }
+
+    #[test]
+    fn test_div_scalar() {
+        newtype!(#[derive(Debug, PartialEq)] struct Meters(f64): From, Into, DivScalar);
+        let m = Meters::from(14f64);
+        assert_eq!(Meters::from(7f64), m / 2f64);
+    }
+
+    #[test]
+    fn test_rem_scalar() {
+        newtype!(#[derive(Debug, PartialEq)] struct Meters(f64): From, Into, RemScalar);
+        let m = Meters::from(14f64);
+        assert_eq!(Meters::from(0f64), m % 2f64);
+    }
+
+    #[test]
+    fn test_from_str() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, FromStr);
+        let m: Miles = "14".parse().unwrap();
+        assert_eq!(Miles::from(14), m);
+        assert!("nope".parse::<Miles>().is_err());
+    }
+
+    #[test]
+    fn test_not() {
+        newtype!(#[derive(Debug, PartialEq)] struct Flags(u8): From, Into, Not);
+        let f = Flags::from(0b0000_1111);
+        assert_eq!(Flags::from(0b1111_0000), !f);
+    }
+
+    #[test]
+    fn test_bitand() {
+        newtype!(#[derive(Debug, PartialEq)] struct Flags(u8): From, Into, BitAnd);
+        assert_eq!(Flags::from(0b0100), Flags::from(0b0110) & Flags::from(0b0101));
+    }
+
+    #[test]
+    fn test_bitor() {
+        newtype!(#[derive(Debug, PartialEq)] struct Flags(u8): From, Into, BitOr);
+        assert_eq!(Flags::from(0b0111), Flags::from(0b0110) | Flags::from(0b0101));
+    }
+
+    #[test]
+    fn test_bitxor() {
+        newtype!(#[derive(Debug, PartialEq)] struct Flags(u8): From, Into, BitXor);
+        assert_eq!(Flags::from(0b0011), Flags::from(0b0110) ^ Flags::from(0b0101));
+    }
+
+    #[test]
+    fn test_shl() {
+        newtype!(#[derive(Debug, PartialEq)] struct Flags(u8): From, Into, Shl);
+        assert_eq!(Flags::from(0b0100), Flags::from(0b0001) << Flags::from(2));
+    }
+
+    #[test]
+    fn test_shr() {
+        newtype!(#[derive(Debug, PartialEq)] struct Flags(u8): From, Into, Shr);
+        assert_eq!(Flags::from(0b0001), Flags::from(0b0100) >> Flags::from(2));
+    }
+
+    #[test]
+    fn test_as_ref() {
+        use std::convert::AsRef;
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, AsRef);
+        let m = Miles::from(14);
+        let r: &u32 = m.as_ref();
+        assert_eq!(&14, r);
+    }
+
+    #[test]
+    fn test_as_mut() {
+        use std::convert::AsMut;
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, AsMut);
+        let mut m = Miles::from(14);
+        *m.as_mut() = 20;
+        assert_eq!(Miles::from(20), m);
+    }
+
+    #[test]
+    fn test_borrow() {
+        use std::borrow::Borrow;
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, Borrow);
+        let m = Miles::from(14);
+        let b: &u32 = m.borrow();
+        assert_eq!(&14, b);
+    }
+
+    #[test]
+    fn test_borrow_mut() {
+        use std::borrow::BorrowMut;
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, Borrow, BorrowMut);
+        let mut m = Miles::from(14);
+        *m.borrow_mut() = 20;
+        assert_eq!(Miles::from(20), m);
+    }
+
+    #[test]
+    fn test_sum() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, Into, Sum);
+        let total: Miles = vec![Miles::from(14), Miles::from(20)].into_iter().sum();
+        assert_eq!(Miles::from(34), total);
+    }
+
+    #[test]
+    fn test_product() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, Into, Product);
+        let total: Miles = vec![Miles::from(2), Miles::from(3), Miles::from(4)].into_iter().product();
+        assert_eq!(Miles::from(24), total);
+    }
+
+    #[test]
+    fn test_constructor() {
+        newtype!(#[derive(Debug, PartialEq)] struct Miles(u32): From, Constructor);
+        assert_eq!(Miles::from(14), Miles::new(14));
+    }
+
+    #[test]
+    fn test_generic_deref() {
+        newtype!(#[derive(Debug, PartialEq)] struct Wrapper<T>(T): Deref, DerefMut, From);
+        let mut w = Wrapper::from(14u32);
+        assert_eq!(*w, 14);
+        *w = 20;
+        assert_eq!(*w, 20);
+    }
+
+    #[test]
+    fn test_generic_display_with_bounds() {
+        struct Wrapper<T>(T);
+        newtype_derive!(Wrapper<T>(T) where { T: ::std::fmt::Display }: Display);
+        assert_eq!(String::from("14"), format!("{}", Wrapper(14u32)));
+    }
 }